@@ -1,9 +1,25 @@
 // Simple Rust module example
-use std::collections::HashMap; // Standard library import
+use std::collections::{BTreeMap, HashMap, HashSet}; // Standard library import
 use crate::utils::helper; // Crate relative import
+use automalar_derive::Summary; // Derive macro for the Summary trait
 
 mod utils; // Declare submodule
 
+// Populates `$obj`'s fields from `$map`, looked up by field name, recursing
+// over the field list one identifier at a time. A missing key returns `None`
+// from the enclosing function (which must return an `Option`) through the
+// `?` inside the expansion; otherwise the expansion evaluates to `Some(())`.
+macro_rules! from_map {
+    ($map:expr, $obj:expr $(,)?) => {
+        Some(())
+    };
+    ($map:expr, $obj:expr, $field:ident $(, $rest:ident)* $(,)?) => {{
+        $obj.$field = *$map.get(stringify!($field))?;
+        from_map!($map, $obj $(, $rest)*)
+    }};
+}
+
+#[derive(Summary)]
 pub struct Point {
     x: i32,
     y: i32,
@@ -17,8 +33,17 @@ impl Point {
     pub fn distance_from_origin(&self) -> f64 {
         ((self.x.pow(2) + self.y.pow(2)) as f64).sqrt()
     }
+
+    // Built on `from_map!`; returns `None` (instead of panicking) if `map` is
+    // missing any of the fields this point needs.
+    pub fn from_map(map: &HashMap<&str, i32>) -> Option<Point> {
+        let mut obj = Point { x: 0, y: 0 };
+        from_map!(map, obj, x, y)?;
+        Some(obj)
+    }
 }
 
+#[derive(Summary)]
 pub enum Status {
     Active,
     Inactive(String), // Enum with data
@@ -28,9 +53,51 @@ pub trait Summary {
     fn summarize(&self) -> String;
 }
 
-impl Summary for Point {
-    fn summarize(&self) -> String {
-        format!("Point({}, {})", self.x, self.y)
+// Declarative policy for deciding a `Point`'s `Status`, built from the free
+// functions `active()`/`inactive()` and chained with `.when()`/`.otherwise()`
+// instead of a hardcoded `if`. `evaluate` walks the condition/fallback chain
+// and returns the resulting `Status`.
+pub struct PointPolicy {
+    status: Status,
+    condition: Option<Box<dyn Fn(&Point) -> bool>>,
+    fallback: Option<Box<PointPolicy>>,
+}
+
+pub fn active() -> PointPolicy {
+    PointPolicy {
+        status: Status::Active,
+        condition: None,
+        fallback: None,
+    }
+}
+
+pub fn inactive(reason: &str) -> PointPolicy {
+    PointPolicy {
+        status: Status::Inactive(reason.to_string()),
+        condition: None,
+        fallback: None,
+    }
+}
+
+impl PointPolicy {
+    pub fn when(mut self, condition: impl Fn(&Point) -> bool + 'static) -> Self {
+        self.condition = Some(Box::new(condition));
+        self
+    }
+
+    pub fn otherwise(mut self, fallback: PointPolicy) -> Self {
+        self.fallback = Some(Box::new(fallback));
+        self
+    }
+
+    pub fn evaluate(&self, p: &Point) -> Status {
+        match (&self.condition, &self.fallback) {
+            (Some(condition), Some(fallback)) if !condition(p) => fallback.evaluate(p),
+            _ => match &self.status {
+                Status::Active => Status::Active,
+                Status::Inactive(reason) => Status::Inactive(reason.clone()),
+            },
+        }
     }
 }
 
@@ -38,18 +105,112 @@ impl Summary for Point {
 fn process_point(p: Point) -> Status {
     println!("Processing point: {}", p.summarize());
     helper(); // Call helper from utils module
-    if p.distance_from_origin() > 10.0 {
-        Status::Inactive("Too far".to_string())
-    } else {
-        Status::Active
-    }
+    active()
+        .when(|p| p.distance_from_origin() <= 10.0)
+        .otherwise(inactive("Too far"))
+        .evaluate(&p)
+}
+
+// Internal helper: expands to its second argument, discarding the token tree
+// given first; fed `1usize`, it is used to count macro repetitions (e.g. literal entries) at compile time.
+macro_rules! replace_expr {
+    ($_t:tt, $sub:expr) => {
+        $sub
+    };
 }
 
 // Macro definition example
+// Collection-builder macros: `create_map!`, `create_set!` and `create_btree!`.
+// Each supports the bare (std container) form plus two prefixed forms that let
+// callers pick the concrete container: `<K, V>;` annotates the type and `in
+// $ty;` selects a `Default`-built container such as a SwissTable-style map.
+// The `HashMap`-backed arms pre-size with `with_capacity` so a literal's
+// entries land in a single allocation instead of growing (and rehashing) one
+// insert at a time.
 macro_rules! create_map {
+    (in $ty:ty; $($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = <$ty>::default();
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+    (<$k:ty, $v:ty>; $($key:expr => $value:expr),* $(,)?) => {
+        {
+            let cap = 0usize $(+ replace_expr!($key, 1usize))*;
+            let mut map: HashMap<$k, $v> = HashMap::with_capacity(cap);
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let cap = 0usize $(+ replace_expr!($key, 1usize))*;
+            let mut map = HashMap::with_capacity(cap);
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+macro_rules! create_set {
+    (in $ty:ty; $($value:expr),* $(,)?) => {
+        {
+            let mut set = <$ty>::default();
+            $(
+                set.insert($value);
+            )*
+            set
+        }
+    };
+    (<$v:ty>; $($value:expr),* $(,)?) => {
+        {
+            let mut set: HashSet<$v> = HashSet::default();
+            $(
+                set.insert($value);
+            )*
+            set
+        }
+    };
+    ($($value:expr),* $(,)?) => {
+        {
+            let mut set = HashSet::new();
+            $(
+                set.insert($value);
+            )*
+            set
+        }
+    };
+}
+
+macro_rules! create_btree {
+    (in $ty:ty; $($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = <$ty>::default();
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+    (<$k:ty, $v:ty>; $($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map: BTreeMap<$k, $v> = BTreeMap::new();
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
     ($($key:expr => $value:expr),* $(,)?) => {
         {
-            let mut map = HashMap::new();
+            let mut map = BTreeMap::new();
             $(
                 map.insert($key, $value);
             )*
@@ -61,5 +222,9 @@ macro_rules! create_map {
 fn main() {
    let p1 = Point::new(3, 4);
    let status = process_point(p1);
+   println!("Resulting status: {}", status.summarize()); // Exercise the derived Summary impl
    let _my_map = create_map!("a" => 1, "b" => 2); // Macro invocation
+   let _typed_map = create_map!(<&str, i32>; "a" => 1, "b" => 2); // Annotated-type form
+   let _fx_map = create_map!(in HashMap<&str, i32>; "a" => 1); // Custom-container form
+   let _round_trip = Point::from_map(&create_map!("x" => 3, "y" => 4)); // from_map! round-trip
 }
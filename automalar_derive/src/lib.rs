@@ -0,0 +1,121 @@
+//! `#[derive(Summary)]` — generates `Summary::summarize` for structs and enums
+//! so callers don't have to hand-write the `impl Summary for ...` boilerplate.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Fields, Index, LitStr};
+
+/// Derives `Summary` for structs and enums.
+///
+/// - Structs expand to `TypeName(field0, field1, ...)`, rendering each field
+///   via its `Display` impl (the field type must support `.to_string()`).
+/// - Enums expand to the variant name plus any payload rendered via `Debug`,
+///   e.g. `Inactive("Too far")` (the quotes come from `String`'s `Debug`).
+///
+/// The emitted name of the type or of a variant can be overridden with
+/// `#[summary(rename = "...")]`. Field names are not emitted, so the attribute
+/// is not read on fields.
+#[proc_macro_derive(Summary, attributes(summary))]
+pub fn derive_summary(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let display_name = renamed(&input.attrs).unwrap_or_else(|| name.to_string());
+
+    let body = match &input.data {
+        Data::Struct(data) => summarize_struct(&display_name, &data.fields),
+        Data::Enum(data) => summarize_enum(data),
+        Data::Union(_) => panic!("#[derive(Summary)] does not support unions"),
+    };
+
+    quote! {
+        impl Summary for #name {
+            fn summarize(&self) -> String {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+fn summarize_struct(display_name: &str, fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let accessors = named.named.iter().map(|f| {
+                let ident = f.ident.as_ref().unwrap();
+                quote! { self.#ident.to_string() }
+            });
+            quote! {
+                format!(concat!(#display_name, "({})"), vec![#(#accessors),*].join(", "))
+            }
+        }
+        Fields::Unnamed(unnamed) => {
+            let accessors = (0..unnamed.unnamed.len()).map(|i| {
+                let idx = Index::from(i);
+                quote! { self.#idx.to_string() }
+            });
+            quote! {
+                format!(concat!(#display_name, "({})"), vec![#(#accessors),*].join(", "))
+            }
+        }
+        Fields::Unit => quote! { #display_name.to_string() },
+    }
+}
+
+fn summarize_enum(data: &DataEnum) -> TokenStream2 {
+    let arms = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let display_name = renamed(&variant.attrs).unwrap_or_else(|| ident.to_string());
+
+        match &variant.fields {
+            Fields::Unit => quote! {
+                Self::#ident => #display_name.to_string(),
+            },
+            Fields::Unnamed(unnamed) => {
+                let bindings: Vec<_> = (0..unnamed.unnamed.len())
+                    .map(|i| syn::Ident::new(&format!("field{i}"), ident.span()))
+                    .collect();
+                quote! {
+                    Self::#ident(#(#bindings),*) => {
+                        format!(concat!(#display_name, "({})"), vec![#(format!("{:?}", #bindings)),*].join(", "))
+                    }
+                }
+            }
+            Fields::Named(named) => {
+                let bindings: Vec<_> = named.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+                quote! {
+                    Self::#ident { #(#bindings),* } => {
+                        format!(concat!(#display_name, "({})"), vec![#(format!("{:?}", #bindings)),*].join(", "))
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        match self {
+            #(#arms)*
+        }
+    }
+}
+
+/// Reads `#[summary(rename = "...")]` off a type or variant, if present.
+fn renamed(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if !attr.path().is_ident("summary") {
+            continue;
+        }
+        let mut renamed = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let lit: LitStr = meta.value()?.parse()?;
+                renamed = Some(lit.value());
+            }
+            Ok(())
+        });
+        if renamed.is_some() {
+            return renamed;
+        }
+    }
+    None
+}